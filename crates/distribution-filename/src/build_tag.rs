@@ -1,3 +1,4 @@
+use std::fmt::{Display, Formatter};
 use std::num::ParseIntError;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -61,3 +62,12 @@ impl FromStr for BuildTag {
         Ok(BuildTag(prefix.parse::<u64>()?, suffix.map(Arc::from)))
     }
 }
+
+impl Display for BuildTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.1 {
+            Some(suffix) => write!(f, "{}{}", self.0, suffix),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
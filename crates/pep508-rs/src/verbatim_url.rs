@@ -573,4 +573,21 @@ mod tests {
             (Cow::Borrowed(Path::new("")), None)
         );
     }
+
+    #[cfg(windows)]
+    #[test]
+    fn from_absolute_path_unc() {
+        let url = VerbatimUrl::from_absolute_path(r"\\server\share\ferris\pkg").unwrap();
+        assert_eq!(url.as_str(), "file://server/share/ferris/pkg");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn from_absolute_path_extended_length() {
+        let url = VerbatimUrl::from_absolute_path(r"\\?\C:\Users\ferris\pkg").unwrap();
+        assert_eq!(url.as_str(), "file:///C:/Users/ferris/pkg");
+
+        let url = VerbatimUrl::from_absolute_path(r"\\?\UNC\server\share\ferris\pkg").unwrap();
+        assert_eq!(url.as_str(), "file://server/share/ferris/pkg");
+    }
 }
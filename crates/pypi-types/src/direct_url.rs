@@ -76,6 +76,20 @@ pub enum VcsKind {
     Svn,
 }
 
+impl VcsKind {
+    /// Returns the [`VcsKind`] for a URL scheme prefix (e.g., `hg` in `hg+https://...`), if the
+    /// prefix names a known version control system.
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix {
+            "git" => Some(Self::Git),
+            "hg" => Some(Self::Hg),
+            "bzr" => Some(Self::Bzr),
+            "svn" => Some(Self::Svn),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for VcsKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -253,6 +253,15 @@ impl ParsedGitUrl {
     }
 }
 
+impl ParsedGitUrl {
+    /// Returns the [`VcsKind`] of the repository.
+    ///
+    /// Git is the only version control system that can be resolved.
+    pub fn vcs_kind(&self) -> VcsKind {
+        VcsKind::Git
+    }
+}
+
 impl TryFrom<Url> for ParsedGitUrl {
     type Error = ParsedUrlError;
 
@@ -332,29 +341,18 @@ impl TryFrom<Url> for ParsedUrl {
 
     fn try_from(url: Url) -> Result<Self, Self::Error> {
         if let Some((prefix, ..)) = url.scheme().split_once('+') {
-            match prefix {
-                "git" => Ok(Self::Git(ParsedGitUrl::try_from(url)?)),
-                "bzr" => Err(ParsedUrlError::UnsupportedUrlPrefix {
-                    prefix: prefix.to_string(),
-                    url: url.to_string(),
-                    message: "Bazaar is not supported",
-                }),
-                "hg" => Err(ParsedUrlError::UnsupportedUrlPrefix {
-                    prefix: prefix.to_string(),
-                    url: url.to_string(),
-                    message: "Mercurial is not supported",
-                }),
-                "svn" => Err(ParsedUrlError::UnsupportedUrlPrefix {
-                    prefix: prefix.to_string(),
-                    url: url.to_string(),
-                    message: "Subversion is not supported",
-                }),
-                _ => Err(ParsedUrlError::UnsupportedUrlPrefix {
-                    prefix: prefix.to_string(),
-                    url: url.to_string(),
-                    message: "Unknown scheme",
-                }),
-            }
+            let message = match VcsKind::from_prefix(prefix) {
+                Some(VcsKind::Git) => return Ok(Self::Git(ParsedGitUrl::try_from(url)?)),
+                Some(VcsKind::Bzr) => "Bazaar is not supported",
+                Some(VcsKind::Hg) => "Mercurial is not supported",
+                Some(VcsKind::Svn) => "Subversion is not supported",
+                None => "Unknown scheme",
+            };
+            Err(ParsedUrlError::UnsupportedUrlPrefix {
+                prefix: prefix.to_string(),
+                url: url.to_string(),
+                message,
+            })
         } else if Path::new(url.path())
             .extension()
             .is_some_and(|ext| ext.eq_ignore_ascii_case("git"))
@@ -453,7 +451,7 @@ impl TryFrom<&ParsedGitUrl> for DirectUrl {
         Ok(Self::VcsUrl {
             url: value.url.repository().to_string(),
             vcs_info: VcsInfo {
-                vcs: VcsKind::Git,
+                vcs: value.vcs_kind(),
                 commit_id: value.url.precise().as_ref().map(ToString::to_string),
                 requested_revision: value.url.reference().as_str().map(ToString::to_string),
             },
@@ -511,7 +509,8 @@ mod tests {
     use anyhow::Result;
     use url::Url;
 
-    use crate::parsed_url::ParsedUrl;
+    use crate::parsed_url::{ParsedUrl, ParsedUrlError};
+    use crate::{DirectUrl, VcsKind};
 
     #[test]
     fn direct_url_from_url() -> Result<()> {
@@ -541,6 +540,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn vcs_kind() -> Result<()> {
+        let url = Url::parse("git+https://github.com/pallets/flask.git")?;
+        let Ok(DirectUrl::VcsUrl { vcs_info, .. }) =
+            DirectUrl::try_from(&ParsedUrl::try_from(url)?)
+        else {
+            panic!("Expected a VCS URL");
+        };
+        assert_eq!(vcs_info.vcs, VcsKind::Git);
+
+        // Other version control systems are recognized, but unsupported.
+        let url = Url::parse("hg+https://hg.example.com/repo")?;
+        let Err(ParsedUrlError::UnsupportedUrlPrefix { message, .. }) = ParsedUrl::try_from(url)
+        else {
+            panic!("Expected an unsupported URL prefix");
+        };
+        assert_eq!(message, "Mercurial is not supported");
+
+        let url = Url::parse("foo+https://example.com/repo")?;
+        let Err(ParsedUrlError::UnsupportedUrlPrefix { message, .. }) = ParsedUrl::try_from(url)
+        else {
+            panic!("Expected an unsupported URL prefix");
+        };
+        assert_eq!(message, "Unknown scheme");
+
+        for kind in [VcsKind::Git, VcsKind::Hg, VcsKind::Bzr, VcsKind::Svn] {
+            assert_eq!(VcsKind::from_prefix(&kind.to_string()), Some(kind));
+        }
+
+        Ok(())
+    }
+
     #[test]
     #[cfg(unix)]
     fn direct_url_from_url_absolute() -> Result<()> {
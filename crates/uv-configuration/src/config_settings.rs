@@ -113,6 +113,20 @@ impl ConfigSettings {
         serde_json::to_string(self).expect("Failed to serialize config settings")
     }
 
+    /// Flatten the settings into a map from key to value, joining any list values with a space.
+    pub fn to_flat_map(&self) -> BTreeMap<String, String> {
+        self.0
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    ConfigSettingValue::String(value) => value.clone(),
+                    ConfigSettingValue::List(values) => values.join(" "),
+                };
+                (key.clone(), value)
+            })
+            .collect()
+    }
+
     /// Merge two sets of config settings, with the values in `self` taking precedence.
     #[must_use]
     pub fn merge(self, other: ConfigSettings) -> ConfigSettings {
@@ -268,4 +282,24 @@ mod tests {
         );
         assert_eq!(settings.escape_for_python(), r#"{"key":"val\\1 {}value"}"#);
     }
+
+    #[test]
+    fn to_flat_map() {
+        let mut settings = ConfigSettings::default();
+        settings.0.insert(
+            "key".to_string(),
+            ConfigSettingValue::String("value".to_string()),
+        );
+        settings.0.insert(
+            "list".to_string(),
+            ConfigSettingValue::List(vec!["value1".to_string(), "value2".to_string()]),
+        );
+        assert_eq!(
+            settings.to_flat_map(),
+            BTreeMap::from([
+                ("key".to_string(), "value".to_string()),
+                ("list".to_string(), "value1 value2".to_string()),
+            ])
+        );
+    }
 }
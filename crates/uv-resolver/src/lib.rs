@@ -15,7 +15,11 @@ pub use python_requirement::PythonRequirement;
 pub use requires_python::{
     RequiresPython, RequiresPythonBound, RequiresPythonError, RequiresPythonRange,
 };
-pub use resolution::{AnnotationStyle, DisplayResolutionGraph, ResolutionGraph};
+pub use resolution::{
+    AnnotatedDist, AnnotatedDistUrlError, AnnotationStyle, DisplayResolutionGraph, DistKind,
+    FilteredDists, FilteredGraph, HashMismatch, InstallationReportItem, MergeError, PackageChange,
+    PipReport, PipReportOptions, ReportDiff, ReportError, ResolutionGraph, VerifyHashesError,
+};
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
     BuildId, DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageVersionsResult,
@@ -0,0 +1,80 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use petgraph::Direction;
+use serde_json::{json, Value};
+
+use distribution_types::Name;
+use pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::resolution::graph::ResolutionGraphNode;
+use crate::ResolutionGraph;
+
+/// The version of the CycloneDX specification that is emitted.
+const SPEC_VERSION: &str = "1.5";
+
+impl ResolutionGraph {
+    /// Export the resolution as a minimal [CycloneDX](https://cyclonedx.org/) SBOM.
+    ///
+    /// Each distinct package (regardless of extras) is emitted as a component, identified by its
+    /// `pkg:pypi` package URL, along with its hashes. The dependencies between packages are
+    /// emitted as dependency relationships.
+    pub fn to_cyclonedx(&self) -> Value {
+        let mut components = BTreeMap::new();
+        let mut dependencies = BTreeMap::<String, BTreeSet<String>>::new();
+
+        for index in self.petgraph.node_indices() {
+            let ResolutionGraphNode::Dist(dist) = &self.petgraph[index] else {
+                continue;
+            };
+            let bom_ref = dist.purl();
+
+            // Extras share a component with the base package.
+            if dist.is_base() {
+                components.insert(
+                    bom_ref.clone(),
+                    json!({
+                        "type": "library",
+                        "bom-ref": bom_ref,
+                        "name": dist.name(),
+                        "version": dist.version.to_string(),
+                        "purl": bom_ref,
+                        "hashes": dist.hashes.iter().map(hash).collect::<Vec<_>>(),
+                    }),
+                );
+            }
+
+            let depends_on = dependencies.entry(bom_ref.clone()).or_default();
+            for dependency in self.petgraph.neighbors_directed(index, Direction::Outgoing) {
+                let ResolutionGraphNode::Dist(dependency) = &self.petgraph[dependency] else {
+                    continue;
+                };
+                let dependency = dependency.purl();
+                if dependency != bom_ref {
+                    depends_on.insert(dependency);
+                }
+            }
+        }
+
+        json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": SPEC_VERSION,
+            "version": 1,
+            "components": components.into_values().collect::<Vec<_>>(),
+            "dependencies": dependencies
+                .into_iter()
+                .map(|(bom_ref, depends_on)| json!({ "ref": bom_ref, "dependsOn": depends_on }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Return the CycloneDX representation of a [`HashDigest`].
+fn hash(hash: &HashDigest) -> Value {
+    let algorithm = match hash.algorithm() {
+        HashAlgorithm::Md5 => "MD5",
+        HashAlgorithm::Sha256 => "SHA-256",
+        HashAlgorithm::Sha384 => "SHA-384",
+        HashAlgorithm::Sha512 => "SHA-512",
+    };
+    json!({ "alg": algorithm, "content": hash.digest })
+}
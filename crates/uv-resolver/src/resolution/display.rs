@@ -1,13 +1,15 @@
 use std::collections::BTreeSet;
 
 use owo_colors::OwoColorize;
+use petgraph::graph::NodeIndex;
 use petgraph::visit::EdgeRef;
 use petgraph::Direction;
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use pubgrub::Range;
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
 use distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
 use pep508_rs::MarkerTree;
-use uv_normalize::PackageName;
+use uv_normalize::{GroupName, PackageName};
 
 use crate::graph_ops::{propagate_markers, Markers};
 use crate::resolution::{RequirementsTxtDist, ResolutionGraphNode};
@@ -27,6 +29,8 @@ pub struct DisplayResolutionGraph<'a> {
     marker_env: &'a ResolverMarkers,
     /// The packages to exclude from the output.
     no_emit_packages: &'a [PackageName],
+    /// The dependency groups to exclude from the output.
+    excluded_groups: &'a [GroupName],
     /// Whether to include hashes in the output.
     show_hashes: bool,
     /// Whether to include extras in the output (e.g., `black[colorama]`).
@@ -91,6 +95,7 @@ impl<'a> DisplayResolutionGraph<'a> {
             resolution: underlying,
             marker_env,
             no_emit_packages,
+            excluded_groups: &[],
             show_hashes,
             include_extras,
             include_markers,
@@ -99,6 +104,16 @@ impl<'a> DisplayResolutionGraph<'a> {
             annotation_style,
         }
     }
+
+    /// Exclude the packages that are only required by the given dependency groups.
+    ///
+    /// A package that is required both by a dependency group and by the project itself is
+    /// retained.
+    #[must_use]
+    pub fn without_groups(mut self, groups: &'a [GroupName]) -> Self {
+        self.excluded_groups = groups;
+        self
+    }
 }
 
 /// Write the graph in the `{name}=={version}` format of requirements.txt that pip uses.
@@ -157,14 +172,23 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         };
 
         // Convert from `AnnotatedDist` to `RequirementsTxtDist`.
-        let petgraph = to_requirements_txt_graph(&self.resolution.petgraph);
+        let petgraph = to_requirements_txt_graph(&self.resolution.petgraph, self.excluded_groups);
 
         // Propagate markers across the graph.
         let petgraph = propagate_markers(petgraph);
 
         // Reduce the graph, such that all nodes for a single package are combined, regardless of
         // the extras.
-        let petgraph = combine_extras(&petgraph);
+        let mut petgraph = combine_extras(&petgraph);
+
+        // Simplify the markers, removing any terms that are implied by the `requires-python`.
+        if let Some(requires_python) = &self.resolution.requires_python {
+            let python_version = Range::from(requires_python.range().clone());
+            for node in petgraph.node_weights_mut() {
+                node.markers = std::mem::take(&mut node.markers)
+                    .simplify_python_versions(python_version.clone());
+            }
+        }
 
         // Collect all packages.
         let mut nodes = petgraph
@@ -272,6 +296,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                             annotation = Some((separator, comment));
                         }
                     },
+                    AnnotationStyle::None => {}
                 }
             }
 
@@ -288,7 +313,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
 
             // If enabled, include indexes to indicate which index was used for each package (e.g.,
             // `# from https://pypi.org/simple`).
-            if self.include_index_annotation {
+            if self.include_index_annotation && self.annotation_style != AnnotationStyle::None {
                 if let Some(index) = node.dist.index() {
                     let url = index.redacted();
                     writeln!(f, "{}", format!("    # from {url}").green())?;
@@ -312,6 +337,8 @@ pub enum AnnotationStyle {
     /// Render each annotation on its own line.
     #[default]
     Split,
+    /// Omit all annotation comments.
+    None,
 }
 
 type ResolutionPetGraph =
@@ -327,12 +354,30 @@ type RequirementsTxtGraph =
 /// [`DisplayResolutionGraphNode`].
 ///
 /// In other words: converts from [`AnnotatedDist`] to [`RequirementsTxtDist`].
-fn to_requirements_txt_graph(graph: &ResolutionPetGraph) -> IntermediatePetGraph {
+///
+/// Any nodes that belong to the `excluded_groups`, along with any nodes that are only reachable
+/// through them, are omitted.
+fn to_requirements_txt_graph(
+    graph: &ResolutionPetGraph,
+    excluded_groups: &[GroupName],
+) -> IntermediatePetGraph {
     let mut next = IntermediatePetGraph::with_capacity(graph.node_count(), graph.edge_count());
     let mut inverse = FxHashMap::with_capacity_and_hasher(graph.node_count(), FxBuildHasher);
 
+    let included = if excluded_groups.is_empty() {
+        None
+    } else {
+        Some(reachable_without_groups(graph, excluded_groups))
+    };
+
     // Re-add the nodes to the reduced graph.
     for index in graph.node_indices() {
+        if included
+            .as_ref()
+            .is_some_and(|included| !included.contains(&index))
+        {
+            continue;
+        }
         match &graph[index] {
             ResolutionGraphNode::Root => {
                 inverse.insert(index, next.add_node(DisplayResolutionGraphNode::Root));
@@ -347,15 +392,46 @@ fn to_requirements_txt_graph(graph: &ResolutionPetGraph) -> IntermediatePetGraph
     // Re-add the edges to the reduced graph.
     for edge in graph.edge_indices() {
         let (source, target) = graph.edge_endpoints(edge).unwrap();
+        let (Some(source), Some(target)) = (inverse.get(&source), inverse.get(&target)) else {
+            continue;
+        };
         let weight = graph[edge].clone();
-        let source = inverse[&source];
-        let target = inverse[&target];
-        next.update_edge(source, target, weight);
+        next.update_edge(*source, *target, weight);
     }
 
     next
 }
 
+/// Return the nodes that are reachable from the root without passing through a node that belongs
+/// to one of the `excluded_groups`.
+fn reachable_without_groups(
+    graph: &ResolutionPetGraph,
+    excluded_groups: &[GroupName],
+) -> FxHashSet<NodeIndex> {
+    let mut stack = graph
+        .node_indices()
+        .filter(|index| matches!(graph[*index], ResolutionGraphNode::Root))
+        .collect::<Vec<_>>();
+    let mut reachable = stack.iter().copied().collect::<FxHashSet<_>>();
+    while let Some(index) = stack.pop() {
+        for dependency in graph.neighbors_directed(index, Direction::Outgoing) {
+            if let ResolutionGraphNode::Dist(dist) = &graph[dependency] {
+                if dist
+                    .dev
+                    .as_ref()
+                    .is_some_and(|group| excluded_groups.contains(group))
+                {
+                    continue;
+                }
+            }
+            if reachable.insert(dependency) {
+                stack.push(dependency);
+            }
+        }
+    }
+    reachable
+}
+
 /// Reduce the graph, such that all nodes for a single package are combined, regardless of
 /// the extras.
 ///
@@ -415,3 +491,179 @@ fn combine_extras(graph: &IntermediatePetGraph) -> RequirementsTxtGraph {
 
     next
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pep440_rs::Version;
+    use pep508_rs::MarkerTree;
+    use uv_normalize::GroupName;
+
+    use crate::resolution::display::UNIVERSAL_MARKERS;
+    use crate::resolution::testing::installed_dist;
+    use crate::resolution::ResolutionGraphNode;
+    use crate::{AnnotationStyle, DisplayResolutionGraph, RequiresPython, ResolutionGraph};
+
+    #[test]
+    fn annotation_style_none() {
+        // `anyio` depends on `idna` and `sniffio`.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let anyio = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("anyio", "4.3.0")));
+        let idna = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("idna", "3.6")));
+        let sniffio = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist(
+                "sniffio", "1.3.1",
+            )));
+        resolution.petgraph.add_edge(root, anyio, MarkerTree::TRUE);
+        resolution.petgraph.add_edge(anyio, idna, MarkerTree::TRUE);
+        resolution
+            .petgraph
+            .add_edge(anyio, sniffio, MarkerTree::TRUE);
+
+        let display = |annotation_style| {
+            DisplayResolutionGraph::new(
+                &resolution,
+                &UNIVERSAL_MARKERS,
+                &[],
+                false,
+                false,
+                false,
+                true,
+                true,
+                annotation_style,
+            )
+            .to_string()
+        };
+
+        // By default, each dependency is annotated with its dependents.
+        let output = display(AnnotationStyle::default());
+        assert!(output.lines().any(|line| line.contains('#')));
+
+        let output = display(AnnotationStyle::None);
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            vec!["anyio==4.3.0", "idna==3.6", "sniffio==1.3.1"]
+        );
+        assert!(!output.lines().any(|line| line.contains('#')));
+    }
+
+    #[test]
+    fn without_groups() {
+        let mut project_dev = installed_dist("project", "0.1.0");
+        project_dev.dev = Some(GroupName::from_str("dev").unwrap());
+
+        // `anyio` is required by the project and by the `dev` group, while `pytest` is only
+        // required by the `dev` group.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let project = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist(
+                "project", "0.1.0",
+            )));
+        let project_dev = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(project_dev));
+        let anyio = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("anyio", "4.3.0")));
+        let pytest = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("pytest", "8.1.1")));
+        resolution
+            .petgraph
+            .add_edge(root, project, MarkerTree::TRUE);
+        resolution
+            .petgraph
+            .add_edge(project, anyio, MarkerTree::TRUE);
+        resolution
+            .petgraph
+            .add_edge(project, project_dev, MarkerTree::TRUE);
+        resolution
+            .petgraph
+            .add_edge(project_dev, anyio, MarkerTree::TRUE);
+        resolution
+            .petgraph
+            .add_edge(project_dev, pytest, MarkerTree::TRUE);
+
+        let display = DisplayResolutionGraph::new(
+            &resolution,
+            &UNIVERSAL_MARKERS,
+            &[],
+            false,
+            false,
+            false,
+            false,
+            false,
+            AnnotationStyle::default(),
+        );
+        assert_eq!(
+            display.to_string().lines().collect::<Vec<_>>(),
+            vec!["anyio==4.3.0", "project==0.1.0", "pytest==8.1.1"]
+        );
+
+        let groups = [GroupName::from_str("dev").unwrap()];
+        assert_eq!(
+            display
+                .without_groups(&groups)
+                .to_string()
+                .lines()
+                .collect::<Vec<_>>(),
+            vec!["anyio==4.3.0", "project==0.1.0"]
+        );
+    }
+
+    #[test]
+    fn simplify_markers() {
+        // The `python_version` terms are implied by the `requires-python`.
+        let mut resolution = ResolutionGraph {
+            requires_python: Some(RequiresPython::greater_than_equal_version(&Version::new([
+                3, 8,
+            ]))),
+            ..ResolutionGraph::default()
+        };
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let anyio = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("anyio", "4.3.0")));
+        let idna = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("idna", "3.6")));
+        resolution.petgraph.add_edge(
+            root,
+            anyio,
+            MarkerTree::from_str(
+                "python_version >= '3.8' and python_version >= '3.7' and sys_platform == 'linux'",
+            )
+            .unwrap(),
+        );
+        resolution.petgraph.add_edge(
+            root,
+            idna,
+            MarkerTree::from_str("python_version >= '3.8' and python_version >= '3.7'").unwrap(),
+        );
+
+        let display = DisplayResolutionGraph::new(
+            &resolution,
+            &UNIVERSAL_MARKERS,
+            &[],
+            false,
+            false,
+            true,
+            false,
+            false,
+            AnnotationStyle::default(),
+        );
+        assert_eq!(
+            display.to_string().lines().collect::<Vec<_>>(),
+            vec!["anyio==4.3.0 ; sys_platform == 'linux'", "idna==3.6"]
+        );
+    }
+}
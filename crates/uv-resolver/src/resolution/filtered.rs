@@ -0,0 +1,153 @@
+use std::collections::VecDeque;
+
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use rustc_hash::FxHashSet;
+
+use distribution_types::Name;
+use pep508_rs::MarkerEnvironment;
+use uv_normalize::PackageName;
+
+use crate::resolution::graph::ResolutionGraphNode;
+use crate::resolution::AnnotatedDist;
+use crate::ResolutionGraph;
+
+impl ResolutionGraph {
+    /// Return a view of the resolution that only includes the packages that apply to the given
+    /// [`MarkerEnvironment`].
+    ///
+    /// The view is lazy: markers are evaluated as the graph is traversed, rather than
+    /// materializing a filtered copy of the graph.
+    pub fn with_marker_filter(&self, environment: &MarkerEnvironment) -> FilteredGraph<'_> {
+        FilteredGraph {
+            graph: self,
+            environment: environment.clone(),
+        }
+    }
+}
+
+/// A view of a [`ResolutionGraph`] that only includes the packages that are reachable from the
+/// root via edges whose markers match a [`MarkerEnvironment`].
+#[derive(Debug, Clone)]
+pub struct FilteredGraph<'a> {
+    graph: &'a ResolutionGraph,
+    environment: MarkerEnvironment,
+}
+
+impl<'a> FilteredGraph<'a> {
+    /// Returns an iterator over the packages that apply to the environment, in breadth-first
+    /// order from the root.
+    pub fn dists(&self) -> FilteredDists<'a, '_> {
+        let queue = self
+            .graph
+            .petgraph
+            .node_indices()
+            .filter(|index| matches!(self.graph.petgraph[*index], ResolutionGraphNode::Root))
+            .collect::<VecDeque<_>>();
+        FilteredDists {
+            graph: self.graph,
+            environment: &self.environment,
+            visited: queue.iter().copied().collect(),
+            queue,
+        }
+    }
+
+    /// Returns `true` if the given package applies to the environment.
+    pub fn contains(&self, name: &PackageName) -> bool {
+        self.dists().any(|dist| dist.name() == name)
+    }
+}
+
+/// An iterator over the packages in a [`FilteredGraph`].
+#[derive(Debug)]
+pub struct FilteredDists<'a, 'env> {
+    graph: &'a ResolutionGraph,
+    environment: &'env MarkerEnvironment,
+    queue: VecDeque<NodeIndex>,
+    visited: FxHashSet<NodeIndex>,
+}
+
+impl<'a> Iterator for FilteredDists<'a, '_> {
+    type Item = &'a AnnotatedDist;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(index) = self.queue.pop_front() {
+            for edge in self
+                .graph
+                .petgraph
+                .edges_directed(index, Direction::Outgoing)
+            {
+                if edge.weight().evaluate(self.environment, &[])
+                    && self.visited.insert(edge.target())
+                {
+                    self.queue.push_back(edge.target());
+                }
+            }
+            if let ResolutionGraphNode::Dist(dist) = &self.graph.petgraph[index] {
+                return Some(dist);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pep508_rs::MarkerTree;
+    use uv_normalize::PackageName;
+
+    use crate::resolution::graph::ResolutionGraphNode;
+    use crate::resolution::testing::{environment, installed_dist};
+    use crate::ResolutionGraph;
+
+    #[test]
+    fn with_marker_filter() {
+        // `colorama` (and its dependency, `wincolor`) are only required on Windows.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let anyio = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("anyio", "4.3.0")));
+        let idna = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("idna", "3.6")));
+        let colorama = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist(
+                "colorama", "0.4.6",
+            )));
+        let wincolor = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist(
+                "wincolor", "1.0.0",
+            )));
+        resolution.petgraph.add_edge(root, anyio, MarkerTree::TRUE);
+        resolution.petgraph.add_edge(anyio, idna, MarkerTree::TRUE);
+        resolution.petgraph.add_edge(
+            root,
+            colorama,
+            MarkerTree::from_str("sys_platform == 'win32'").unwrap(),
+        );
+        resolution
+            .petgraph
+            .add_edge(colorama, wincolor, MarkerTree::TRUE);
+
+        let environment = environment();
+        let filtered = resolution.with_marker_filter(&environment);
+        let names = filtered
+            .dists()
+            .map(|dist| dist.metadata.name.as_ref())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["anyio", "idna"]);
+        assert!(!filtered.contains(&PackageName::from_str("colorama").unwrap()));
+        assert!(!filtered.contains(&PackageName::from_str("wincolor").unwrap()));
+
+        // On Windows, every package applies.
+        let environment = environment.with_sys_platform("win32");
+        let filtered = resolution.with_marker_filter(&environment);
+        assert_eq!(filtered.dists().count(), 4);
+    }
+}
@@ -1,18 +1,21 @@
+use std::sync::OnceLock;
+
 use indexmap::IndexSet;
 use petgraph::{
     graph::{Graph, NodeIndex},
     Directed, Direction,
 };
+use pubgrub::Range;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
 use distribution_types::{
-    Dist, DistributionMetadata, Name, ResolutionDiagnostic, ResolvedDist, VersionId,
+    CachedDist, Dist, DistributionMetadata, Name, ResolutionDiagnostic, ResolvedDist, VersionId,
     VersionOrUrlRef,
 };
 use pep440_rs::{Version, VersionSpecifier};
 use pep508_rs::{MarkerEnvironment, MarkerTree, MarkerTreeKind, VerbatimUrl};
 use pypi_types::{HashDigest, ParsedUrlError, Requirement, VerbatimParsedUrl, Yanked};
-use uv_configuration::{Constraints, Overrides};
+use uv_configuration::{ConfigSettings, Constraints, Overrides};
 use uv_distribution::Metadata;
 use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -21,7 +24,8 @@ use crate::pins::FilePins;
 use crate::preferences::Preferences;
 use crate::python_requirement::PythonTarget;
 use crate::redirect::url_to_precise;
-use crate::resolution::AnnotatedDist;
+use crate::resolution::report::combined_markers;
+use crate::resolution::{AnnotatedDist, PipReport, ReportError};
 use crate::resolution_mode::ResolutionStrategy;
 use crate::resolver::{Resolution, ResolutionDependencyEdge, ResolutionPackage};
 use crate::{
@@ -319,6 +323,7 @@ impl ResolutionGraph {
             dev: dev.clone(),
             hashes,
             metadata,
+            cached_direct_url: OnceLock::new(),
         }));
         inverse.insert(
             PackageRef {
@@ -460,7 +465,7 @@ impl ResolutionGraph {
     }
 
     /// Returns an iterator over the distinct packages in the graph.
-    fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
+    pub fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
         self.petgraph
             .node_indices()
             .filter_map(move |index| match &self.petgraph[index] {
@@ -484,11 +489,64 @@ impl ResolutionGraph {
         self.dists().any(|dist| dist.name() == name)
     }
 
+    /// Return the markers under which the given package is installed, i.e., the disjunction of
+    /// the markers on every path from the root to any node for the package (including its
+    /// extras).
+    ///
+    /// Returns [`MarkerTree::FALSE`] if the package isn't part of the resolution, and
+    /// [`MarkerTree::TRUE`] if it's installed unconditionally.
+    pub fn marker_for(&self, name: &PackageName) -> MarkerTree {
+        let mut marker = MarkerTree::FALSE;
+        for (dist, combined) in combined_markers(self) {
+            if dist.name() == name {
+                marker.or(combined);
+            }
+        }
+        match &self.requires_python {
+            Some(requires_python) => {
+                marker.simplify_python_versions(Range::from(requires_python.range().clone()))
+            }
+            None => marker,
+        }
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
     }
 
+    /// Create a [`PipReport`] for the resolution, as in [`PipReport::from_resolution`].
+    ///
+    /// `on_progress` is invoked after each item is added to the report, with the number of
+    /// completed items and the total number of items.
+    pub fn to_pip_report(
+        &self,
+        dists: &[CachedDist],
+        requirements: &[Requirement],
+        environment: &MarkerEnvironment,
+        config_settings: &ConfigSettings,
+        on_progress: impl FnMut(usize, usize),
+    ) -> Result<PipReport, ReportError> {
+        PipReport::from_resolution_with_progress(
+            self,
+            dists,
+            requirements,
+            environment,
+            config_settings,
+            on_progress,
+        )
+    }
+
+    /// Create a [`PipReport`] that only includes the changes relative to a `previous` resolution,
+    /// as in [`PipReport::from_diff`].
+    pub fn diff_as_report(
+        &self,
+        previous: &ResolutionGraph,
+        environment: &MarkerEnvironment,
+    ) -> Result<PipReport, ReportError> {
+        PipReport::from_diff(self, previous, environment)
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
@@ -649,6 +707,178 @@ impl ResolutionGraph {
             Some(&package_markers[&(version.clone(), url.cloned())])
         }
     }
+
+    /// Return the sorted set of extras that were activated for the given package, across all of
+    /// its nodes in the graph.
+    pub fn extras_for(&self, name: &PackageName) -> Vec<ExtraName> {
+        let mut extras = self
+            .dists()
+            .filter(|dist| dist.name() == name)
+            .filter_map(|dist| dist.extra.clone())
+            .collect::<Vec<_>>();
+        extras.sort_unstable();
+        extras.dedup();
+        extras
+    }
+
+    /// Return the dependencies that were activated by each extra in the graph, as a list of
+    /// `(package, extra, dependency)` triples.
+    pub fn extra_edges(&self) -> Vec<(PackageName, ExtraName, PackageName)> {
+        let mut edges = self
+            .petgraph
+            .node_indices()
+            .filter_map(|index| match &self.petgraph[index] {
+                ResolutionGraphNode::Dist(dist) => {
+                    dist.extra.as_ref().map(|extra| (index, dist, extra))
+                }
+                ResolutionGraphNode::Root => None,
+            })
+            .flat_map(|(index, dist, extra)| {
+                self.petgraph
+                    .neighbors_directed(index, Direction::Outgoing)
+                    .filter_map(move |dependency| match &self.petgraph[dependency] {
+                        ResolutionGraphNode::Dist(dependency)
+                            if dependency.name() != dist.name() =>
+                        {
+                            Some((
+                                dist.name().clone(),
+                                extra.clone(),
+                                dependency.name().clone(),
+                            ))
+                        }
+                        _ => None,
+                    })
+            })
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
+
+    /// Return the dependency cycles in the graph, as the sorted names of the packages involved in
+    /// each cycle.
+    ///
+    /// Nodes for the same package (e.g., `flask` and `flask[dotenv]`) are treated as a single
+    /// package, such that a package's dependency on its own extras isn't reported as a cycle.
+    pub fn find_cycles(&self) -> Vec<Vec<PackageName>> {
+        let mut packages = Graph::<&PackageName, ()>::new();
+        let mut inverse = FxHashMap::default();
+        for dist in self.dists() {
+            inverse
+                .entry(dist.name())
+                .or_insert_with(|| packages.add_node(dist.name()));
+        }
+        for edge in self.petgraph.edge_indices() {
+            let (source, target) = self.petgraph.edge_endpoints(edge).unwrap();
+            let (ResolutionGraphNode::Dist(source), ResolutionGraphNode::Dist(target)) =
+                (&self.petgraph[source], &self.petgraph[target])
+            else {
+                continue;
+            };
+            if source.name() != target.name() {
+                packages.update_edge(inverse[source.name()], inverse[target.name()], ());
+            }
+        }
+
+        let mut cycles = petgraph::algo::tarjan_scc(&packages)
+            .into_iter()
+            .filter(|component| component.len() > 1)
+            .map(|component| {
+                let mut cycle = component
+                    .into_iter()
+                    .map(|index| packages[index].clone())
+                    .collect::<Vec<_>>();
+                cycle.sort_unstable();
+                cycle
+            })
+            .collect::<Vec<_>>();
+        cycles.sort_unstable();
+        cycles
+    }
+
+    /// Return the longest chain of dependencies in the graph, from a root to a leaf.
+    ///
+    /// Consecutive nodes for the same package (e.g., `flask[dotenv]` and `flask`) are counted, and
+    /// returned, once. A dependency cycle is treated as a single link in the chain, which includes
+    /// every package in the cycle, in name order.
+    pub fn longest_dependency_chain(&self) -> Vec<&AnnotatedDist> {
+        // Collapse each cycle into a single component, ordering its nodes by name. The components
+        // are returned in reverse topological order, so the dependencies of each component are
+        // visited before the component itself.
+        let components = petgraph::algo::tarjan_scc(&self.petgraph)
+            .into_iter()
+            .map(|component| {
+                let mut dists = component
+                    .iter()
+                    .filter_map(|index| match &self.petgraph[*index] {
+                        ResolutionGraphNode::Dist(dist) => Some((dist.name(), *index)),
+                        ResolutionGraphNode::Root => None,
+                    })
+                    .collect::<Vec<_>>();
+                dists.sort_unstable();
+                (component, dists)
+            })
+            .collect::<Vec<_>>();
+        let mut component_of = vec![0; self.petgraph.node_count()];
+        for (position, (component, _)) in components.iter().enumerate() {
+            for index in component {
+                component_of[index.index()] = position;
+            }
+        }
+
+        // For each component, the length (in distinct packages) of the longest chain starting at
+        // the component, and the component that comes next in that chain.
+        let mut longest: Vec<(usize, Option<usize>)> = Vec::with_capacity(components.len());
+        for (position, (component, dists)) in components.iter().enumerate() {
+            let mut names = dists.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            names.dedup();
+            let mut best = (names.len(), None);
+            for index in component {
+                for dependency in self
+                    .petgraph
+                    .neighbors_directed(*index, Direction::Outgoing)
+                {
+                    let next = component_of[dependency.index()];
+                    if next == position {
+                        continue;
+                    }
+                    let (length, _) = longest[next];
+                    // Moving between nodes for the same package doesn't lengthen the chain.
+                    let overlap = usize::from(names.last().is_some_and(|last| {
+                        components[next].1.first().map(|(name, _)| name) == Some(last)
+                    }));
+                    let length = names.len() + length - overlap;
+                    if length > best.0 {
+                        best = (length, Some(next));
+                    }
+                }
+            }
+            longest.push(best);
+        }
+
+        let start = self
+            .petgraph
+            .node_indices()
+            .filter(|index| matches!(self.petgraph[*index], ResolutionGraphNode::Root))
+            .map(|index| component_of[index.index()])
+            .max_by_key(|component| longest[*component].0);
+
+        let mut chain: Vec<&AnnotatedDist> = Vec::new();
+        let mut next = start;
+        while let Some(component) = next {
+            for (_, index) in &components[component].1 {
+                let ResolutionGraphNode::Dist(dist) = &self.petgraph[*index] else {
+                    continue;
+                };
+                if chain.last().is_some_and(|last| last.name() == dist.name()) {
+                    continue;
+                }
+                chain.push(dist);
+            }
+            next = longest[component].1;
+        }
+        chain
+    }
 }
 
 impl From<ResolutionGraph> for distribution_types::Resolution {
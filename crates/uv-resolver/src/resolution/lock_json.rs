@@ -0,0 +1,332 @@
+use std::collections::BTreeSet;
+
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use rustc_hash::FxHashMap;
+use serde_json::{json, Value};
+
+use distribution_types::Name;
+use pep440_rs::Version;
+use uv_normalize::{ExtraName, GroupName, PackageName};
+
+use crate::resolution::graph::ResolutionGraphNode;
+use crate::resolution::report::combined_markers;
+use crate::resolution::AnnotatedDist;
+use crate::ResolutionGraph;
+
+/// The version of the format emitted by [`ResolutionGraph::to_lock_json`].
+const LOCK_JSON_VERSION: u64 = 1;
+
+/// The key by which nodes are ordered, when more than one node could come next in the
+/// topological order.
+type NodeKey<'a> = (
+    &'a PackageName,
+    &'a Version,
+    Option<&'a ExtraName>,
+    Option<&'a GroupName>,
+);
+
+impl ResolutionGraph {
+    /// Export the full resolution graph, including transitive edges, as JSON.
+    ///
+    /// The output has the following shape:
+    ///
+    /// ```json
+    /// {
+    ///   "version": 1,
+    ///   "nodes": [
+    ///     {
+    ///       "id": 0,
+    ///       "name": "anyio",
+    ///       "version": "4.3.0",
+    ///       "extra": null,
+    ///       "dev": null,
+    ///       "url": "https://files.pythonhosted.org/.../anyio-4.3.0-py3-none-any.whl",
+    ///       "hashes": ["sha256:..."],
+    ///       "marker": null
+    ///     }
+    ///   ],
+    ///   "edges": [{ "from": null, "to": 0, "marker": null }]
+    /// }
+    /// ```
+    ///
+    /// Each node is a package (or one of its extras or dependency groups), along with the combined
+    /// markers under which it's required, or `null` if it's always required. Each edge is a
+    /// dependency between two nodes, by `id`, along with the markers under which it applies; edges
+    /// from the root have a `from` of `null`.
+    ///
+    /// Nodes are emitted in topological order (dependents before their dependencies), breaking
+    /// ties (and cycles) by name, version, extra, and group, such that the output is stable
+    /// across resolutions of the same requirements.
+    pub fn to_lock_json(&self) -> Value {
+        // The markers are returned in node order, omitting the root.
+        let markers = self
+            .petgraph
+            .node_indices()
+            .filter(|index| matches!(self.petgraph[*index], ResolutionGraphNode::Dist(_)))
+            .zip(combined_markers(self))
+            .map(|(index, (_, marker))| (index, marker))
+            .collect::<FxHashMap<_, _>>();
+
+        let order = topological_order(self);
+        let ids = order
+            .iter()
+            .enumerate()
+            .map(|(id, index)| (*index, id))
+            .collect::<FxHashMap<_, _>>();
+
+        let nodes = order
+            .iter()
+            .map(|index| {
+                let dist = dist(self, *index);
+                json!({
+                    "id": ids[index],
+                    "name": dist.name(),
+                    "version": dist.version.to_string(),
+                    "extra": dist.extra,
+                    "dev": dist.dev,
+                    "url": dist.url().map(|url| url.to_string()),
+                    "hashes": dist.hashes.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                    "marker": markers[index].try_to_string(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut edges = self
+            .petgraph
+            .edge_references()
+            .filter_map(|edge| {
+                let to = *ids.get(&edge.target())?;
+                let from = match self.petgraph[edge.source()] {
+                    ResolutionGraphNode::Root => None,
+                    ResolutionGraphNode::Dist(_) => Some(ids[&edge.source()]),
+                };
+                Some((from, to, edge.weight().try_to_string()))
+            })
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+        edges.dedup();
+
+        json!({
+            "version": LOCK_JSON_VERSION,
+            "nodes": nodes,
+            "edges": edges
+                .into_iter()
+                .map(|(from, to, marker)| json!({ "from": from, "to": to, "marker": marker }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Return the distribution nodes of the [`ResolutionGraph`] in topological order, breaking ties by
+/// [`NodeKey`].
+///
+/// The nodes of each cycle are emitted together, ordered by [`NodeKey`], at the position of the
+/// cycle's least node.
+fn topological_order(resolution: &ResolutionGraph) -> Vec<NodeIndex> {
+    // Collapse each cycle into a single component, ordering its nodes by key.
+    let components = tarjan_scc(&resolution.petgraph)
+        .into_iter()
+        .filter_map(|component| {
+            let mut nodes = component
+                .into_iter()
+                .filter(|index| matches!(resolution.petgraph[*index], ResolutionGraphNode::Dist(_)))
+                .map(|index| (key(resolution, index), index))
+                .collect::<Vec<_>>();
+            nodes.sort_unstable();
+            (!nodes.is_empty()).then_some(nodes)
+        })
+        .collect::<Vec<_>>();
+    let component_of = components
+        .iter()
+        .enumerate()
+        .flat_map(|(component, nodes)| nodes.iter().map(move |(_, index)| (*index, component)))
+        .collect::<FxHashMap<_, _>>();
+
+    // Count the edges into each component from every other component, ignoring the root.
+    let mut in_degree = vec![0usize; components.len()];
+    for edge in resolution.petgraph.edge_references() {
+        if let (Some(source), Some(target)) = (
+            component_of.get(&edge.source()),
+            component_of.get(&edge.target()),
+        ) {
+            if source != target {
+                in_degree[*target] += 1;
+            }
+        }
+    }
+
+    let mut ready = components
+        .iter()
+        .enumerate()
+        .filter(|(component, _)| in_degree[*component] == 0)
+        .map(|(component, nodes)| (nodes[0].0, component))
+        .collect::<BTreeSet<_>>();
+    let mut order = Vec::with_capacity(component_of.len());
+    while let Some((_, component)) = ready.pop_first() {
+        for (_, index) in &components[component] {
+            order.push(*index);
+            for target in resolution
+                .petgraph
+                .neighbors_directed(*index, Direction::Outgoing)
+            {
+                let target = component_of[&target];
+                if target != component {
+                    in_degree[target] -= 1;
+                    if in_degree[target] == 0 {
+                        ready.insert((components[target][0].0, target));
+                    }
+                }
+            }
+        }
+    }
+    order
+}
+
+/// Return the [`NodeKey`] of the distribution at the given index.
+fn key(resolution: &ResolutionGraph, index: NodeIndex) -> NodeKey<'_> {
+    let dist = dist(resolution, index);
+    (
+        dist.name(),
+        &dist.version,
+        dist.extra.as_ref(),
+        dist.dev.as_ref(),
+    )
+}
+
+/// Return the distribution at the given index, which must not be the root.
+fn dist(resolution: &ResolutionGraph, index: NodeIndex) -> &AnnotatedDist {
+    match &resolution.petgraph[index] {
+        ResolutionGraphNode::Dist(dist) => dist,
+        ResolutionGraphNode::Root => unreachable!("The root is not a distribution"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    use pep508_rs::MarkerTree;
+    use serde_json::json;
+    use uv_normalize::ExtraName;
+
+    use crate::resolution::graph::ResolutionGraphNode;
+    use crate::resolution::testing::installed_dist;
+    use crate::resolution::AnnotatedDist;
+    use crate::ResolutionGraph;
+
+    /// Build a resolution in which `anyio[trio]` depends on `anyio` and `trio`, which depends on
+    /// `idna`; `anyio` also depends on `idna`, and `colorama` is only required on Windows. `trio`
+    /// and `outcome` form a cycle.
+    ///
+    /// If `reverse` is set, the nodes and edges are added in the reverse order.
+    fn graph(reverse: bool) -> ResolutionGraph {
+        let mut nodes = vec![
+            ("idna", "3.6", None),
+            ("outcome", "1.3.0", None),
+            ("trio", "0.25.0", None),
+            ("colorama", "0.4.6", None),
+            ("anyio", "4.3.0", Some("trio")),
+            ("anyio", "4.3.0", None),
+        ];
+        let mut edges = vec![
+            (None, "anyio", None, MarkerTree::TRUE),
+            (None, "anyio", Some("trio"), MarkerTree::TRUE),
+            (
+                None,
+                "colorama",
+                None,
+                MarkerTree::from_str("sys_platform == 'win32'").unwrap(),
+            ),
+            (Some(("anyio", None)), "idna", None, MarkerTree::TRUE),
+            (
+                Some(("anyio", Some("trio"))),
+                "anyio",
+                None,
+                MarkerTree::TRUE,
+            ),
+            (
+                Some(("anyio", Some("trio"))),
+                "trio",
+                None,
+                MarkerTree::TRUE,
+            ),
+            (Some(("trio", None)), "idna", None, MarkerTree::TRUE),
+            (Some(("trio", None)), "outcome", None, MarkerTree::TRUE),
+            (Some(("outcome", None)), "trio", None, MarkerTree::TRUE),
+        ];
+        if reverse {
+            nodes.reverse();
+            edges.reverse();
+        }
+
+        let mut graph = ResolutionGraph::default();
+        let root = graph.petgraph.add_node(ResolutionGraphNode::Root);
+        let mut indices = BTreeMap::new();
+        for (name, version, extra) in nodes {
+            let index = graph
+                .petgraph
+                .add_node(ResolutionGraphNode::Dist(AnnotatedDist {
+                    extra: extra.map(|extra| ExtraName::from_str(extra).unwrap()),
+                    ..installed_dist(name, version)
+                }));
+            indices.insert((name, extra), index);
+        }
+        for (source, name, extra, marker) in edges {
+            let source = source.map_or(root, |source| indices[&source]);
+            graph
+                .petgraph
+                .add_edge(source, indices[&(name, extra)], marker);
+        }
+        graph
+    }
+
+    #[test]
+    fn to_lock_json() {
+        let lock = graph(false).to_lock_json();
+        let nodes = lock["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|node| {
+                (
+                    node["name"].as_str().unwrap(),
+                    node["extra"].as_str(),
+                    node["marker"].as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            nodes,
+            vec![
+                ("anyio", Some("trio"), None),
+                ("anyio", None, None),
+                ("colorama", None, Some("sys_platform == 'win32'")),
+                // The nodes in the cycle are emitted together.
+                ("outcome", None, None),
+                ("trio", None, None),
+                ("idna", None, None),
+            ]
+        );
+        assert_eq!(
+            lock["edges"],
+            json!([
+                { "from": null, "to": 0, "marker": null },
+                { "from": null, "to": 1, "marker": null },
+                { "from": null, "to": 2, "marker": "sys_platform == 'win32'" },
+                { "from": 0, "to": 1, "marker": null },
+                { "from": 0, "to": 4, "marker": null },
+                { "from": 1, "to": 5, "marker": null },
+                { "from": 3, "to": 4, "marker": null },
+                { "from": 4, "to": 3, "marker": null },
+                { "from": 4, "to": 5, "marker": null },
+            ])
+        );
+
+        // The output doesn't depend on the order in which the graph was constructed.
+        assert_eq!(graph(true).to_lock_json(), lock);
+    }
+}
@@ -1,34 +1,100 @@
 use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::Path;
+use std::sync::OnceLock;
 
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use url::Url;
+
+use distribution_filename::DistExtension;
 use distribution_types::{
-    BuiltDist, Dist, DistributionMetadata, IndexUrl, Name, ResolvedDist, SourceDist,
-    VersionOrUrlRef,
+    BuiltDist, Dist, DistributionMetadata, IndexLocations, IndexUrl, InstalledDist, Name,
+    ResolvedDist, SourceDist, ToUrlError, VersionOrUrlRef,
 };
 use pep440_rs::Version;
-use pypi_types::HashDigest;
+use pypi_types::{
+    DirInfo, DirectUrl, HashAlgorithm, HashDigest, ParsedArchiveUrl, ParsedDirectoryUrl,
+    ParsedGitUrl, ParsedPathUrl, ParsedUrl, ParsedUrlError,
+};
 use uv_distribution::Metadata;
+use uv_extract::hash::Hasher;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 
 pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
+pub use crate::resolution::filtered::{FilteredDists, FilteredGraph};
 pub use crate::resolution::graph::ResolutionGraph;
 pub(crate) use crate::resolution::graph::ResolutionGraphNode;
+use crate::resolution::report::with_hashes;
+pub use crate::resolution::report::{
+    DistKind, InstallationReportItem, MergeError, PackageChange, PipReport, PipReportOptions,
+    ReportDiff, ReportError,
+};
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
 
+mod cyclonedx;
 mod display;
+mod filtered;
 mod graph;
+mod lock_json;
+mod report;
 mod requirements_txt;
+mod spdx;
+#[cfg(test)]
+mod testing;
+
+/// A disagreement between a hash recorded for a distribution and the hash of its artifact.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Hash mismatch for `{algorithm}` digest: expected `{expected}`, computed `{actual}`")]
+pub struct HashMismatch {
+    pub algorithm: HashAlgorithm,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// An error encountered while verifying the hashes of a distribution.
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyHashesError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    Mismatch(#[from] HashMismatch),
+}
+
+/// An error encountered while determining the [`DirectUrl`] of a distribution.
+#[derive(Debug, thiserror::Error)]
+pub enum AnnotatedDistUrlError {
+    #[error(transparent)]
+    ParsedUrl(#[from] ParsedUrlError),
+
+    #[error(transparent)]
+    FileUrl(#[from] ToUrlError),
+}
+
+/// The characters that are percent-encoded in a Package URL: everything other than alphanumerics,
+/// the unreserved `-`, `.`, `_`, and `~`, and the `:` and `/` that the specification leaves
+/// unencoded (e.g., in a `vcs_url` qualifier).
+const PURL: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b':')
+    .remove(b'/');
 
 /// A pinned package with its resolved distribution and metadata. The [`ResolvedDist`] refers to a
 /// specific distribution (e.g., a specific wheel), while the [`Metadata23`] refers to the metadata
 /// for the package-version pair.
 #[derive(Debug, Clone)]
-pub(crate) struct AnnotatedDist {
+pub struct AnnotatedDist {
     pub(crate) dist: ResolvedDist,
     pub(crate) version: Version,
     pub(crate) extra: Option<ExtraName>,
     pub(crate) dev: Option<GroupName>,
     pub(crate) hashes: Vec<HashDigest>,
     pub(crate) metadata: Metadata,
+    /// The PEP 610 [`DirectUrl`] for the distribution, computed on first access.
+    pub(crate) cached_direct_url: OnceLock<Option<DirectUrl>>,
 }
 
 impl AnnotatedDist {
@@ -38,6 +104,81 @@ impl AnnotatedDist {
         self.extra.is_none() && self.dev.is_none()
     }
 
+    /// Returns `true` if the [`AnnotatedDist`] is sourced from the local filesystem (i.e., a local
+    /// wheel, source archive, or directory, or an installed egg or editable), as opposed to a
+    /// registry, a remote URL, or a Git repository.
+    pub fn is_local(&self) -> bool {
+        match &self.dist {
+            ResolvedDist::Installed(dist) => match dist {
+                InstalledDist::Registry(_) => false,
+                InstalledDist::Url(dist) => dist.url.scheme() == "file",
+                InstalledDist::EggInfoFile(_) => true,
+                InstalledDist::EggInfoDirectory(_) => true,
+                InstalledDist::LegacyEditable(_) => true,
+            },
+            ResolvedDist::Installable(dist) => match dist {
+                Dist::Built(dist) => match dist {
+                    BuiltDist::Registry(_) => false,
+                    BuiltDist::DirectUrl(_) => false,
+                    BuiltDist::Path(_) => true,
+                },
+                Dist::Source(dist) => match dist {
+                    SourceDist::Registry(_) => false,
+                    SourceDist::DirectUrl(_) => false,
+                    SourceDist::Git(_) => false,
+                    SourceDist::Path(_) => true,
+                    SourceDist::Directory(_) => true,
+                },
+            },
+        }
+    }
+
+    /// Returns the hashes of the distribution.
+    pub fn hashes(&self) -> &[HashDigest] {
+        &self.hashes
+    }
+
+    /// Verify that the artifact at `path` matches the hashes of the distribution.
+    ///
+    /// Returns a [`HashMismatch`] for the first hash that disagrees with the artifact. If the
+    /// distribution has no hashes, there's nothing to verify.
+    pub fn verify_hashes(&self, path: &Path) -> Result<(), VerifyHashesError> {
+        if self.hashes.is_empty() {
+            return Ok(());
+        }
+
+        let mut hashers = self
+            .hashes
+            .iter()
+            .map(|hash| Hasher::from(hash.algorithm()))
+            .collect::<Vec<_>>();
+        let mut file = fs_err::File::open(path)?;
+        let mut buffer = vec![0; 8192];
+        loop {
+            let read = file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            for hasher in &mut hashers {
+                hasher.update(&buffer[..read]);
+            }
+        }
+
+        for (expected, hasher) in self.hashes.iter().zip(hashers) {
+            let actual = HashDigest::from(hasher);
+            if actual.digest != expected.digest {
+                return Err(HashMismatch {
+                    algorithm: expected.algorithm(),
+                    expected: expected.digest.to_string(),
+                    actual: actual.digest.to_string(),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the [`IndexUrl`] of the distribution, if it is from a registry.
     pub(crate) fn index(&self) -> Option<&IndexUrl> {
         match &self.dist {
@@ -58,6 +199,112 @@ impl AnnotatedDist {
             },
         }
     }
+
+    /// Returns the [`IndexUrl`] of the distribution, if it is from a registry or was provided as
+    /// a direct URL within one of the given [`IndexLocations`].
+    pub fn index_in<'a>(&'a self, index_locations: &'a IndexLocations) -> Option<&'a IndexUrl> {
+        if let Some(index) = self.index() {
+            return Some(index);
+        }
+        let ResolvedDist::Installable(dist) = &self.dist else {
+            return None;
+        };
+        let location = match dist {
+            Dist::Built(BuiltDist::DirectUrl(dist)) => &dist.location,
+            Dist::Source(SourceDist::DirectUrl(dist)) => &dist.location,
+            _ => return None,
+        };
+        index_locations
+            .indexes()
+            .find(|index| is_url_prefix(index.url(), location))
+    }
+
+    /// Returns the PEP 610 [`DirectUrl`] of the distribution, including any hashes for archives.
+    ///
+    /// Distributions from a registry are included as an archive URL pointing at the file on the
+    /// index, such that their hashes are reported.
+    ///
+    /// The [`DirectUrl`] is computed on first access, such that repeated calls are cheap. Errors
+    /// are not memoised: if the URL can't be determined, each call recomputes it and returns the
+    /// error again.
+    pub fn direct_url(&self) -> Result<Option<&DirectUrl>, AnnotatedDistUrlError> {
+        if let Some(direct_url) = self.cached_direct_url.get() {
+            return Ok(direct_url.as_ref());
+        }
+        let direct_url = resolved_direct_url(&self.dist, &self.hashes)?;
+        Ok(self.cached_direct_url.get_or_init(|| direct_url).as_ref())
+    }
+
+    /// Returns the URL from which the distribution was (or would be) fetched, if known.
+    ///
+    /// Unlike [`AnnotatedDist::direct_url`], this includes the URL of the file for distributions
+    /// from a registry.
+    pub fn url(&self) -> Option<Url> {
+        match &self.dist {
+            ResolvedDist::Installed(InstalledDist::Url(dist)) => Some(dist.url.clone()),
+            ResolvedDist::Installed(_) => None,
+            ResolvedDist::Installable(dist) => match dist {
+                Dist::Built(dist) => match dist {
+                    BuiltDist::Registry(dist) => dist.best_wheel().file.url.to_url().ok(),
+                    BuiltDist::DirectUrl(dist) => Some(dist.location.clone()),
+                    BuiltDist::Path(dist) => Some(dist.url.to_url()),
+                },
+                Dist::Source(dist) => match dist {
+                    SourceDist::Registry(dist) => dist.file.url.to_url().ok(),
+                    SourceDist::DirectUrl(dist) => Some(dist.location.clone()),
+                    SourceDist::Git(dist) => Some(dist.url.to_url()),
+                    SourceDist::Path(dist) => Some(dist.url.to_url()),
+                    SourceDist::Directory(dist) => Some(dist.url.to_url()),
+                },
+            },
+        }
+    }
+
+    /// Returns the [Package URL](https://github.com/package-url/purl-spec) of the distribution,
+    /// e.g., `pkg:pypi/anyio@4.3.0`.
+    ///
+    /// Distributions from a Git repository include a `vcs_url` qualifier, while distributions
+    /// from a remote direct URL include a `download_url` qualifier. Local distributions (see
+    /// [`AnnotatedDist::is_local`]) have no qualifier, as their paths are specific to the machine
+    /// that produced the resolution.
+    pub fn purl(&self) -> String {
+        let qualifier = match &self.dist {
+            _ if self.is_local() => None,
+            ResolvedDist::Installable(Dist::Built(BuiltDist::DirectUrl(dist))) => {
+                Some(("download_url", dist.url.to_string()))
+            }
+            ResolvedDist::Installable(Dist::Source(SourceDist::DirectUrl(dist))) => {
+                Some(("download_url", dist.url.to_string()))
+            }
+            ResolvedDist::Installable(Dist::Source(SourceDist::Git(dist))) => {
+                let mut vcs_url = format!("git+{}", dist.git.repository());
+                if let Some(rev) = dist
+                    .git
+                    .precise()
+                    .map(|precise| precise.to_string())
+                    .or_else(|| dist.git.reference().as_str().map(ToString::to_string))
+                {
+                    vcs_url.push('@');
+                    vcs_url.push_str(&rev);
+                }
+                Some(("vcs_url", vcs_url))
+            }
+            ResolvedDist::Installable(_) | ResolvedDist::Installed(_) => None,
+        };
+
+        let mut purl = format!(
+            "pkg:pypi/{}@{}",
+            self.name(),
+            utf8_percent_encode(&self.version.to_string(), PURL),
+        );
+        if let Some((key, value)) = qualifier {
+            purl.push('?');
+            purl.push_str(key);
+            purl.push('=');
+            purl.extend(utf8_percent_encode(&value, PURL));
+        }
+        purl
+    }
 }
 
 impl Name for AnnotatedDist {
@@ -77,3 +324,966 @@ impl Display for AnnotatedDist {
         Display::fmt(&self.dist, f)
     }
 }
+
+/// Returns `true` if `prefix` is a prefix of `url`, i.e., if both share an origin and the path
+/// segments of `prefix` are a prefix of those of `url`.
+fn is_url_prefix(prefix: &Url, url: &Url) -> bool {
+    if prefix.scheme() != url.scheme()
+        || prefix.host_str() != url.host_str()
+        || prefix.port_or_known_default() != url.port_or_known_default()
+    {
+        return false;
+    }
+    let (Some(prefix), Some(mut url)) = (prefix.path_segments(), url.path_segments()) else {
+        return false;
+    };
+    prefix
+        .filter(|segment| !segment.is_empty())
+        .all(|segment| url.next() == Some(segment))
+}
+
+/// Returns the PEP 610 [`DirectUrl`] for a [`ResolvedDist`], attaching the given hashes to any
+/// archive.
+///
+/// For an installed distribution, this is the [`DirectUrl`] from its `direct_url.json`; for any
+/// other distribution, it matches what would be written to `direct_url.json` on installation
+/// (with the exception of registry distributions, see [`parsed_url`]).
+pub(crate) fn resolved_direct_url(
+    dist: &ResolvedDist,
+    hashes: &[HashDigest],
+) -> Result<Option<DirectUrl>, AnnotatedDistUrlError> {
+    match dist {
+        ResolvedDist::Installed(dist) => Ok(installed_direct_url(dist)),
+        ResolvedDist::Installable(dist) => {
+            let direct_url = DirectUrl::try_from(&parsed_url(dist)?)?;
+            Ok(Some(with_hashes(direct_url, hashes)))
+        }
+    }
+}
+
+/// Returns the PEP 610 [`DirectUrl`] of an installed distribution, if it was installed from a
+/// direct URL.
+///
+/// Legacy editable installs don't have a `direct_url.json`, so they're represented by an editable
+/// local directory pointing at the `.egg-link` target, as recorded.
+pub(crate) fn installed_direct_url(dist: &InstalledDist) -> Option<DirectUrl> {
+    match dist {
+        InstalledDist::Url(dist) => Some((*dist.direct_url).clone()),
+        InstalledDist::LegacyEditable(dist) => Some(DirectUrl::LocalDirectory {
+            url: dist.target_url.to_string(),
+            dir_info: DirInfo {
+                editable: Some(true),
+            },
+        }),
+        InstalledDist::Registry(_)
+        | InstalledDist::EggInfoFile(_)
+        | InstalledDist::EggInfoDirectory(_) => None,
+    }
+}
+
+/// Returns the [`ParsedUrl`] for a [`Dist`].
+///
+/// Distributions from a registry are represented by the URL of the file on the index, such that
+/// their hashes are retained.
+fn parsed_url(dist: &Dist) -> Result<ParsedUrl, ToUrlError> {
+    Ok(match dist {
+        Dist::Built(dist) => match dist {
+            BuiltDist::Registry(dist) => ParsedUrl::Archive(ParsedArchiveUrl::from_source(
+                dist.best_wheel().file.url.to_url()?,
+                None,
+                DistExtension::Wheel,
+            )),
+            BuiltDist::DirectUrl(dist) => ParsedUrl::Archive(ParsedArchiveUrl::from_source(
+                dist.location.clone(),
+                None,
+                DistExtension::Wheel,
+            )),
+            BuiltDist::Path(dist) => ParsedUrl::Path(ParsedPathUrl::from_source(
+                dist.install_path.clone(),
+                DistExtension::Wheel,
+                dist.url.to_url(),
+            )),
+        },
+        Dist::Source(dist) => match dist {
+            SourceDist::Registry(dist) => ParsedUrl::Archive(ParsedArchiveUrl::from_source(
+                dist.file.url.to_url()?,
+                None,
+                DistExtension::Source(dist.ext),
+            )),
+            SourceDist::DirectUrl(dist) => ParsedUrl::Archive(ParsedArchiveUrl::from_source(
+                dist.location.clone(),
+                dist.subdirectory.clone(),
+                DistExtension::Source(dist.ext),
+            )),
+            SourceDist::Git(dist) => ParsedUrl::Git(ParsedGitUrl::from_source(
+                dist.git.repository().clone(),
+                dist.git.reference().clone(),
+                dist.git.precise(),
+                dist.subdirectory.clone(),
+            )),
+            SourceDist::Path(dist) => ParsedUrl::Path(ParsedPathUrl::from_source(
+                dist.install_path.clone(),
+                DistExtension::Source(dist.ext),
+                dist.url.to_url(),
+            )),
+            SourceDist::Directory(dist) => ParsedUrl::Directory(ParsedDirectoryUrl::from_source(
+                dist.install_path.clone(),
+                dist.editable,
+                dist.r#virtual,
+                dist.url.to_url(),
+            )),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use distribution_filename::{SourceDistExtension, WheelFilename};
+    use distribution_types::{
+        BuiltDist, DirectUrlBuiltDist, DirectorySourceDist, Dist, File, FileLocation,
+        GitSourceDist, IndexLocations, IndexUrl, InstalledDirectUrlDist, InstalledDist,
+        InstalledEggInfoDirectory, InstalledEggInfoFile, InstalledLegacyEditable, Name,
+        PathBuiltDist, PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist,
+        ResolvedDist, SourceDist, UrlString,
+    };
+    use pep440_rs::Version;
+    use pep508_rs::{MarkerTree, VerbatimUrl};
+    use pypi_types::{DirInfo, DirectUrl, HashAlgorithm, HashDigest};
+    use url::Url;
+    use uv_git::{GitReference, GitSha, GitUrl};
+    use uv_normalize::{ExtraName, PackageName};
+
+    use crate::resolution::graph::ResolutionGraphNode;
+    use crate::resolution::testing::installed_dist;
+    use crate::resolution::{AnnotatedDist, AnnotatedDistUrlError, VerifyHashesError};
+    use crate::ResolutionGraph;
+
+    fn git_dist(name: &str, version: &str, repository: &str) -> AnnotatedDist {
+        let repository = Url::parse(repository).unwrap();
+        let mut dist = installed_dist(name, version);
+        dist.dist = ResolvedDist::Installable(Dist::Source(SourceDist::Git(GitSourceDist {
+            name: PackageName::from_str(name).unwrap(),
+            git: Box::new(GitUrl::from_commit(
+                repository.clone(),
+                GitReference::Tag(version.to_string()),
+                GitSha::from_str("2a6aac9b8fcb2b70e3a4ce0a4ee3185d0ba3c0ed").unwrap(),
+            )),
+            subdirectory: None,
+            url: VerbatimUrl::from_url(repository),
+        })));
+        dist
+    }
+
+    fn file(filename: &str) -> File {
+        File {
+            dist_info_metadata: false,
+            filename: filename.to_string(),
+            hashes: vec![],
+            requires_python: None,
+            size: None,
+            upload_time_utc_ms: None,
+            url: FileLocation::AbsoluteUrl(UrlString::from(
+                Url::parse(&format!("https://files.pythonhosted.org/{filename}")).unwrap(),
+            )),
+            yanked: None,
+        }
+    }
+
+    #[test]
+    fn hashes() {
+        let dist = AnnotatedDist {
+            hashes: vec![HashDigest::from_str(
+                "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8",
+            )
+            .unwrap()],
+            ..installed_dist("anyio", "4.3.0")
+        };
+        assert_eq!(dist.hashes(), dist.hashes.as_slice());
+        assert_eq!(dist.hashes().len(), 1);
+    }
+
+    #[test]
+    fn extra_edges() {
+        let gpu = ExtraName::from_str("gpu").unwrap();
+        let mut a_gpu = installed_dist("a", "1.0.0");
+        a_gpu.extra = Some(gpu.clone());
+
+        // `a[gpu]` depends on `a` and `cuda-dep`; `a` depends on `b`.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let a = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("a", "1.0.0")));
+        let a_gpu = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(a_gpu));
+        let b = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("b", "1.0.0")));
+        let cuda_dep = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist(
+                "cuda-dep", "12.0.0",
+            )));
+        resolution.petgraph.add_edge(root, a_gpu, MarkerTree::TRUE);
+        resolution.petgraph.add_edge(a_gpu, a, MarkerTree::TRUE);
+        resolution
+            .petgraph
+            .add_edge(a_gpu, cuda_dep, MarkerTree::TRUE);
+        resolution.petgraph.add_edge(a, b, MarkerTree::TRUE);
+
+        assert_eq!(
+            resolution.extra_edges(),
+            vec![(
+                PackageName::from_str("a").unwrap(),
+                gpu,
+                PackageName::from_str("cuda-dep").unwrap(),
+            )]
+        );
+    }
+
+    #[test]
+    fn to_cyclonedx() {
+        let sha256 = HashDigest::from_str(
+            "sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+        )
+        .unwrap();
+
+        // `anyio` depends on `idna`.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let anyio = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(AnnotatedDist {
+                hashes: vec![sha256],
+                ..installed_dist("anyio", "4.3.0")
+            }));
+        let idna = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("idna", "3.6")));
+        resolution.petgraph.add_edge(root, anyio, MarkerTree::TRUE);
+        resolution.petgraph.add_edge(anyio, idna, MarkerTree::TRUE);
+
+        let sbom = resolution.to_cyclonedx();
+        assert_eq!(sbom["bomFormat"], "CycloneDX");
+        assert_eq!(
+            sbom["components"],
+            serde_json::json!([
+                {
+                    "type": "library",
+                    "bom-ref": "pkg:pypi/anyio@4.3.0",
+                    "name": "anyio",
+                    "version": "4.3.0",
+                    "purl": "pkg:pypi/anyio@4.3.0",
+                    "hashes": [
+                        {
+                            "alg": "SHA-256",
+                            "content": "cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+                        },
+                    ],
+                },
+                {
+                    "type": "library",
+                    "bom-ref": "pkg:pypi/idna@3.6",
+                    "name": "idna",
+                    "version": "3.6",
+                    "purl": "pkg:pypi/idna@3.6",
+                    "hashes": [],
+                },
+            ])
+        );
+        assert_eq!(
+            sbom["dependencies"],
+            serde_json::json!([
+                { "ref": "pkg:pypi/anyio@4.3.0", "dependsOn": ["pkg:pypi/idna@3.6"] },
+                { "ref": "pkg:pypi/idna@3.6", "dependsOn": [] },
+            ])
+        );
+    }
+
+    #[test]
+    fn to_spdx() {
+        let sha256 = HashDigest::from_str(
+            "sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+        )
+        .unwrap();
+
+        // `anyio` depends on `idna`.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let anyio = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(AnnotatedDist {
+                hashes: vec![sha256],
+                ..installed_dist("anyio", "4.3.0")
+            }));
+        let idna = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("idna", "3.6")));
+        resolution.petgraph.add_edge(root, anyio, MarkerTree::TRUE);
+        resolution.petgraph.add_edge(anyio, idna, MarkerTree::TRUE);
+
+        let sbom = resolution.to_spdx();
+        assert_eq!(sbom["spdxVersion"], "SPDX-2.3");
+        assert_eq!(
+            sbom["packages"],
+            serde_json::json!([
+                {
+                    "SPDXID": "SPDXRef-Package-anyio-4.3.0",
+                    "name": "anyio",
+                    "versionInfo": "4.3.0",
+                    "downloadLocation": "NOASSERTION",
+                    "filesAnalyzed": false,
+                    "checksums": [
+                        {
+                            "algorithm": "SHA256",
+                            "checksumValue": "cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+                        },
+                    ],
+                },
+                {
+                    "SPDXID": "SPDXRef-Package-idna-3.6",
+                    "name": "idna",
+                    "versionInfo": "3.6",
+                    "downloadLocation": "NOASSERTION",
+                    "filesAnalyzed": false,
+                    "checksums": [],
+                },
+            ])
+        );
+        assert_eq!(
+            sbom["relationships"],
+            serde_json::json!([
+                {
+                    "spdxElementId": "SPDXRef-Package-anyio-4.3.0",
+                    "relationshipType": "DEPENDS_ON",
+                    "relatedSpdxElement": "SPDXRef-Package-idna-3.6",
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn purl() {
+        let dist = installed_dist("anyio", "4.3.0");
+        assert_eq!(dist.purl(), "pkg:pypi/anyio@4.3.0");
+
+        // The `+` of a local version is percent-encoded.
+        let dist = installed_dist("anyio", "4.3.0+local");
+        assert_eq!(dist.purl(), "pkg:pypi/anyio@4.3.0%2Blocal");
+
+        let dist = git_dist("anyio", "4.3.0", "https://github.com/agronholm/anyio");
+        assert_eq!(
+            dist.purl(),
+            "pkg:pypi/anyio@4.3.0?vcs_url=git%2Bhttps://github.com/agronholm/anyio%402a6aac9b8fcb2b70e3a4ce0a4ee3185d0ba3c0ed"
+        );
+    }
+
+    #[test]
+    fn extras_for() {
+        let mut resolution = ResolutionGraph::default();
+        for extra in [None, Some("trio"), Some("doc"), Some("trio")] {
+            let mut dist = installed_dist("anyio", "4.3.0");
+            dist.extra = extra.map(|extra| ExtraName::from_str(extra).unwrap());
+            resolution
+                .petgraph
+                .add_node(ResolutionGraphNode::Dist(dist));
+        }
+        resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(installed_dist("idna", "3.6")));
+
+        assert_eq!(
+            resolution.extras_for(&PackageName::from_str("anyio").unwrap()),
+            vec![
+                ExtraName::from_str("doc").unwrap(),
+                ExtraName::from_str("trio").unwrap(),
+            ]
+        );
+        assert!(resolution
+            .extras_for(&PackageName::from_str("idna").unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn longest_dependency_chain() {
+        let mut flask_dotenv = installed_dist("flask", "3.0.3");
+        flask_dotenv.extra = Some(ExtraName::from_str("dotenv").unwrap());
+
+        // `flask[dotenv] -> flask -> jinja2 -> markupsafe` is longer than
+        // `flask[dotenv] -> python-dotenv`, and `idna` is a leaf.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let mut add = |dist| {
+            resolution
+                .petgraph
+                .add_node(ResolutionGraphNode::Dist(dist))
+        };
+        let flask_dotenv = add(flask_dotenv);
+        let flask = add(installed_dist("flask", "3.0.3"));
+        let jinja2 = add(installed_dist("jinja2", "3.1.4"));
+        let markupsafe = add(installed_dist("markupsafe", "2.1.5"));
+        let python_dotenv = add(installed_dist("python-dotenv", "1.0.1"));
+        let idna = add(installed_dist("idna", "3.6"));
+        for (source, target) in [
+            (root, flask_dotenv),
+            (root, idna),
+            (flask_dotenv, flask),
+            (flask_dotenv, python_dotenv),
+            (flask, jinja2),
+            (jinja2, markupsafe),
+            // A cycle shouldn't affect the result.
+            (markupsafe, flask),
+        ] {
+            resolution
+                .petgraph
+                .add_edge(source, target, MarkerTree::TRUE);
+        }
+
+        let chain = resolution.longest_dependency_chain();
+        assert_eq!(
+            chain
+                .iter()
+                .map(|dist| dist.name().as_ref())
+                .collect::<Vec<_>>(),
+            vec!["flask", "jinja2", "markupsafe"]
+        );
+        // The chain starts at the first node for `flask`, i.e., `flask[dotenv]`.
+        assert_eq!(chain[0].extra, Some(ExtraName::from_str("dotenv").unwrap()));
+        assert!(ResolutionGraph::default()
+            .longest_dependency_chain()
+            .is_empty());
+    }
+
+    #[test]
+    fn longest_dependency_chain_with_cycle() {
+        // `b -> c -> d -> b` is a cycle, entered at `b` from `a` and at `c` from the root, and
+        // left from `d`. The cycle counts as a single link, so the longest chain runs through all
+        // of its packages.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let mut add = |name| {
+            resolution
+                .petgraph
+                .add_node(ResolutionGraphNode::Dist(installed_dist(name, "1.0.0")))
+        };
+        let a = add("a");
+        let b = add("b");
+        let c = add("c");
+        let d = add("d");
+        let e = add("e");
+        let f = add("f");
+        for (source, target) in [
+            (root, c),
+            (root, a),
+            (a, b),
+            (b, c),
+            (c, d),
+            (d, b),
+            (d, e),
+            (e, f),
+        ] {
+            resolution
+                .petgraph
+                .add_edge(source, target, MarkerTree::TRUE);
+        }
+
+        assert_eq!(
+            resolution
+                .longest_dependency_chain()
+                .iter()
+                .map(|dist| dist.name().as_ref())
+                .collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d", "e", "f"]
+        );
+    }
+
+    #[test]
+    fn find_cycles() {
+        let mut a_b = installed_dist("a", "1.0.0");
+        a_b.extra = Some(ExtraName::from_str("b").unwrap());
+
+        // `a[b]` depends on `b`, which depends on `a`; `c` and `d` depend on each other; `e`
+        // isn't part of a cycle.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let mut add = |dist| {
+            resolution
+                .petgraph
+                .add_node(ResolutionGraphNode::Dist(dist))
+        };
+        let a = add(installed_dist("a", "1.0.0"));
+        let a_b = add(a_b);
+        let b = add(installed_dist("b", "1.0.0"));
+        let c = add(installed_dist("c", "1.0.0"));
+        let d = add(installed_dist("d", "1.0.0"));
+        let e = add(installed_dist("e", "1.0.0"));
+        for (source, target) in [
+            (root, a_b),
+            (root, c),
+            (a_b, a),
+            (a_b, b),
+            (b, a),
+            (c, d),
+            (d, c),
+            (d, e),
+        ] {
+            resolution
+                .petgraph
+                .add_edge(source, target, MarkerTree::TRUE);
+        }
+
+        let name = |name| PackageName::from_str(name).unwrap();
+        assert_eq!(
+            resolution.find_cycles(),
+            vec![vec![name("a"), name("b")], vec![name("c"), name("d")]]
+        );
+        assert!(ResolutionGraph::default().find_cycles().is_empty());
+    }
+
+    #[test]
+    fn marker_for() {
+        let mut x_y = installed_dist("x", "1.0.0");
+        x_y.extra = Some(ExtraName::from_str("y").unwrap());
+
+        // `a` is required on Windows, and via `x[y]` on Linux; `b` is required unconditionally,
+        // and via `c` on Windows.
+        let mut resolution = ResolutionGraph::default();
+        let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+        let mut add = |dist| {
+            resolution
+                .petgraph
+                .add_node(ResolutionGraphNode::Dist(dist))
+        };
+        let a = add(installed_dist("a", "1.0.0"));
+        let b = add(installed_dist("b", "1.0.0"));
+        let c = add(installed_dist("c", "1.0.0"));
+        let x = add(installed_dist("x", "1.0.0"));
+        let x_y = add(x_y);
+        let linux = MarkerTree::from_str("sys_platform == 'linux'").unwrap();
+        let windows = MarkerTree::from_str("sys_platform == 'win32'").unwrap();
+        for (source, target, marker) in [
+            (root, a, windows.clone()),
+            (root, x_y, linux.clone()),
+            (x_y, x, MarkerTree::TRUE),
+            (x_y, a, MarkerTree::TRUE),
+            (root, b, MarkerTree::TRUE),
+            (root, c, windows.clone()),
+            (c, b, MarkerTree::TRUE),
+        ] {
+            resolution.petgraph.add_edge(source, target, marker);
+        }
+
+        let name = |name| PackageName::from_str(name).unwrap();
+        let mut expected = linux.clone();
+        expected.or(windows.clone());
+        assert_eq!(resolution.marker_for(&name("a")), expected);
+        assert_eq!(resolution.marker_for(&name("b")), MarkerTree::TRUE);
+        assert_eq!(resolution.marker_for(&name("c")), windows);
+        assert_eq!(resolution.marker_for(&name("x")), linux);
+        assert_eq!(resolution.marker_for(&name("z")), MarkerTree::FALSE);
+    }
+
+    #[test]
+    fn direct_url() {
+        let dist = installed_dist("anyio", "4.3.0");
+        assert_eq!(dist.direct_url().unwrap(), None);
+
+        let dist = git_dist("anyio", "4.3.0", "https://github.com/agronholm/anyio");
+        let Some(DirectUrl::VcsUrl { url, vcs_info, .. }) = dist.direct_url().unwrap() else {
+            panic!("Expected a VCS URL: {:?}", dist.direct_url());
+        };
+        assert_eq!(url, "https://github.com/agronholm/anyio");
+        assert_eq!(
+            vcs_info.commit_id.as_deref(),
+            Some("2a6aac9b8fcb2b70e3a4ce0a4ee3185d0ba3c0ed")
+        );
+        assert_eq!(vcs_info.requested_revision.as_deref(), Some("4.3.0"));
+
+        // Subsequent calls should return the cached value, rather than recomputing it.
+        let first = dist.direct_url().unwrap().unwrap();
+        let second = dist.direct_url().unwrap().unwrap();
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn direct_url_error() {
+        // The index provided a relative file URL with an invalid base.
+        let mut dist = installed_dist("anyio", "4.3.0");
+        dist.dist =
+            ResolvedDist::Installable(Dist::Source(SourceDist::Registry(RegistrySourceDist {
+                name: PackageName::from_str("anyio").unwrap(),
+                version: Version::from_str("4.3.0").unwrap(),
+                file: Box::new(File {
+                    dist_info_metadata: false,
+                    filename: "anyio-4.3.0.tar.gz".to_string(),
+                    hashes: vec![],
+                    requires_python: None,
+                    size: None,
+                    upload_time_utc_ms: None,
+                    url: FileLocation::RelativeUrl(
+                        "not a url".to_string(),
+                        "anyio-4.3.0.tar.gz".to_string(),
+                    ),
+                    yanked: None,
+                }),
+                ext: SourceDistExtension::TarGz,
+                index: IndexUrl::Pypi(VerbatimUrl::parse_url("https://pypi.org/simple").unwrap()),
+                wheels: vec![],
+            })));
+        assert!(matches!(
+            dist.direct_url(),
+            Err(AnnotatedDistUrlError::FileUrl(_))
+        ));
+        // The error isn't memoised, so it's returned on every call.
+        assert!(matches!(
+            dist.direct_url(),
+            Err(AnnotatedDistUrlError::FileUrl(_))
+        ));
+
+        // An installed distribution without a `direct_url.json` legitimately has no direct URL.
+        let dist = installed_dist("anyio", "4.3.0");
+        assert!(matches!(dist.direct_url(), Ok(None)));
+    }
+
+    #[test]
+    fn resolved_direct_url() {
+        let hashes = vec![HashDigest {
+            algorithm: HashAlgorithm::Sha256,
+            digest: "f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6".into(),
+        }];
+        let filename = WheelFilename::from_str("anyio-4.3.0-py3-none-any.whl").unwrap();
+        let archive = VerbatimUrl::parse_url(
+            "https://files.example.com/packages/anyio-4.3.0-py3-none-any.whl",
+        )
+        .unwrap();
+        let wheel = VerbatimUrl::parse_url("file:///wheels/anyio-4.3.0-py3-none-any.whl").unwrap();
+        let directory = VerbatimUrl::parse_url("file:///workspace/anyio").unwrap();
+        let installed = DirectUrl::LocalDirectory {
+            url: "file:///workspace/anyio".to_string(),
+            dir_info: DirInfo {
+                editable: Some(true),
+            },
+        };
+
+        let cases = [
+            (
+                "installed registry dist",
+                installed_dist("anyio", "4.3.0").dist,
+                serde_json::Value::Null,
+            ),
+            (
+                "installed direct URL dist",
+                ResolvedDist::Installed(InstalledDist::Url(InstalledDirectUrlDist {
+                    name: PackageName::from_str("anyio").unwrap(),
+                    version: Version::from_str("4.3.0").unwrap(),
+                    direct_url: Box::new(installed.clone()),
+                    url: directory.to_url(),
+                    editable: true,
+                    path: PathBuf::from("/site-packages/anyio-4.3.0.dist-info"),
+                })),
+                serde_json::to_value(&installed).unwrap(),
+            ),
+            (
+                "direct URL wheel",
+                ResolvedDist::Installable(Dist::Built(BuiltDist::DirectUrl(DirectUrlBuiltDist {
+                    filename: filename.clone(),
+                    location: archive.to_url(),
+                    url: archive,
+                }))),
+                serde_json::json!({
+                    "url": "https://files.example.com/packages/anyio-4.3.0-py3-none-any.whl",
+                    "archive_info": {
+                        "hashes": {
+                            "sha256": "f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6"
+                        }
+                    }
+                }),
+            ),
+            (
+                "path wheel",
+                ResolvedDist::Installable(Dist::Built(BuiltDist::Path(PathBuiltDist {
+                    filename,
+                    install_path: wheel.to_file_path().unwrap(),
+                    url: wheel,
+                }))),
+                serde_json::json!({
+                    "url": "file:///wheels/anyio-4.3.0-py3-none-any.whl",
+                    "archive_info": {
+                        "hashes": {
+                            "sha256": "f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6"
+                        }
+                    }
+                }),
+            ),
+            (
+                "Git sdist",
+                git_dist("anyio", "4.3.0", "https://github.com/agronholm/anyio").dist,
+                serde_json::json!({
+                    "url": "https://github.com/agronholm/anyio",
+                    "vcs_info": {
+                        "vcs": "git",
+                        "commit_id": "2a6aac9b8fcb2b70e3a4ce0a4ee3185d0ba3c0ed",
+                        "requested_revision": "4.3.0"
+                    }
+                }),
+            ),
+            (
+                "directory sdist",
+                ResolvedDist::Installable(Dist::Source(SourceDist::Directory(
+                    DirectorySourceDist {
+                        name: PackageName::from_str("anyio").unwrap(),
+                        install_path: directory.to_file_path().unwrap(),
+                        editable: true,
+                        r#virtual: false,
+                        url: directory,
+                    },
+                ))),
+                serde_json::to_value(&installed).unwrap(),
+            ),
+        ];
+
+        for (case, dist, expected) in cases {
+            let direct_url = super::resolved_direct_url(&dist, &hashes).unwrap();
+            assert_eq!(
+                serde_json::to_value(&direct_url).unwrap(),
+                expected,
+                "{case}"
+            );
+        }
+    }
+
+    #[test]
+    fn is_local() {
+        let filename = WheelFilename::from_str("anyio-4.3.0-py3-none-any.whl").unwrap();
+        let archive = VerbatimUrl::parse_url(
+            "https://files.example.com/packages/anyio-4.3.0-py3-none-any.whl",
+        )
+        .unwrap();
+        let wheel = VerbatimUrl::parse_url("file:///wheels/anyio-4.3.0-py3-none-any.whl").unwrap();
+        let sdist = VerbatimUrl::parse_url("file:///sdists/anyio-4.3.0.tar.gz").unwrap();
+        let directory = VerbatimUrl::parse_url("file:///workspace/anyio").unwrap();
+        let name = PackageName::from_str("anyio").unwrap();
+        let version = Version::from_str("4.3.0").unwrap();
+
+        let cases = [
+            (
+                "installed registry dist",
+                installed_dist("anyio", "4.3.0").dist,
+                false,
+            ),
+            (
+                "installed direct URL dist",
+                ResolvedDist::Installed(InstalledDist::Url(InstalledDirectUrlDist {
+                    name: name.clone(),
+                    version: version.clone(),
+                    direct_url: Box::new(DirectUrl::LocalDirectory {
+                        url: directory.to_string(),
+                        dir_info: DirInfo { editable: None },
+                    }),
+                    url: directory.to_url(),
+                    editable: false,
+                    path: PathBuf::from("/site-packages/anyio-4.3.0.dist-info"),
+                })),
+                true,
+            ),
+            (
+                "installed egg-info file",
+                ResolvedDist::Installed(InstalledDist::EggInfoFile(InstalledEggInfoFile {
+                    name: name.clone(),
+                    version: version.clone(),
+                    path: PathBuf::from("/site-packages/anyio-4.3.0.egg-info"),
+                })),
+                true,
+            ),
+            (
+                "installed egg-info directory",
+                ResolvedDist::Installed(InstalledDist::EggInfoDirectory(
+                    InstalledEggInfoDirectory {
+                        name: name.clone(),
+                        version: version.clone(),
+                        path: PathBuf::from("/site-packages/anyio-4.3.0.egg-info"),
+                    },
+                )),
+                true,
+            ),
+            (
+                "installed legacy editable",
+                ResolvedDist::Installed(InstalledDist::LegacyEditable(InstalledLegacyEditable {
+                    name: name.clone(),
+                    version: version.clone(),
+                    egg_link: PathBuf::from("/site-packages/anyio.egg-link"),
+                    target: directory.to_file_path().unwrap(),
+                    target_url: directory.to_url(),
+                    egg_info: PathBuf::from("/workspace/anyio/anyio.egg-info"),
+                })),
+                true,
+            ),
+            (
+                "registry wheel",
+                ResolvedDist::Installable(Dist::Built(BuiltDist::Registry(RegistryBuiltDist {
+                    wheels: vec![RegistryBuiltWheel {
+                        filename: filename.clone(),
+                        file: Box::new(file("anyio-4.3.0-py3-none-any.whl")),
+                        index: IndexUrl::Pypi(
+                            VerbatimUrl::parse_url("https://pypi.org/simple").unwrap(),
+                        ),
+                    }],
+                    best_wheel_index: 0,
+                    sdist: None,
+                }))),
+                false,
+            ),
+            (
+                "direct URL wheel",
+                ResolvedDist::Installable(Dist::Built(BuiltDist::DirectUrl(DirectUrlBuiltDist {
+                    filename: filename.clone(),
+                    location: archive.to_url(),
+                    url: archive,
+                }))),
+                false,
+            ),
+            (
+                "path wheel",
+                ResolvedDist::Installable(Dist::Built(BuiltDist::Path(PathBuiltDist {
+                    filename,
+                    install_path: wheel.to_file_path().unwrap(),
+                    url: wheel,
+                }))),
+                true,
+            ),
+            (
+                "registry sdist",
+                ResolvedDist::Installable(Dist::Source(SourceDist::Registry(RegistrySourceDist {
+                    name: name.clone(),
+                    version,
+                    file: Box::new(file("anyio-4.3.0.tar.gz")),
+                    ext: SourceDistExtension::TarGz,
+                    index: IndexUrl::Pypi(
+                        VerbatimUrl::parse_url("https://pypi.org/simple").unwrap(),
+                    ),
+                    wheels: vec![],
+                }))),
+                false,
+            ),
+            (
+                "Git sdist",
+                git_dist("anyio", "4.3.0", "https://github.com/agronholm/anyio").dist,
+                false,
+            ),
+            (
+                "path sdist",
+                ResolvedDist::Installable(Dist::Source(SourceDist::Path(PathSourceDist {
+                    name: name.clone(),
+                    install_path: sdist.to_file_path().unwrap(),
+                    ext: SourceDistExtension::TarGz,
+                    url: sdist,
+                }))),
+                true,
+            ),
+            (
+                "directory sdist",
+                ResolvedDist::Installable(Dist::Source(SourceDist::Directory(
+                    DirectorySourceDist {
+                        name,
+                        install_path: directory.to_file_path().unwrap(),
+                        editable: false,
+                        r#virtual: false,
+                        url: directory,
+                    },
+                ))),
+                true,
+            ),
+        ];
+
+        for (case, dist, expected) in cases {
+            let mut annotated = installed_dist("anyio", "4.3.0");
+            annotated.dist = dist;
+            assert_eq!(annotated.is_local(), expected, "{case}");
+
+            // Any direct URL agrees with the classification.
+            if let Some(direct_url) = annotated.direct_url().unwrap() {
+                let url = match direct_url {
+                    DirectUrl::LocalDirectory { url, .. }
+                    | DirectUrl::ArchiveUrl { url, .. }
+                    | DirectUrl::VcsUrl { url, .. } => url,
+                };
+                assert_eq!(url.starts_with("file://"), expected, "{case}");
+            }
+
+            // Local paths are omitted from the Package URL.
+            if expected {
+                assert_eq!(annotated.purl(), "pkg:pypi/anyio@4.3.0", "{case}");
+            }
+        }
+    }
+
+    #[test]
+    fn index_in() {
+        let url = VerbatimUrl::parse_url(
+            "https://mirror.example.com/simple/packages/anyio-4.3.0-py3-none-any.whl",
+        )
+        .unwrap();
+        let mut dist = installed_dist("anyio", "4.3.0");
+        dist.dist =
+            ResolvedDist::Installable(Dist::Built(BuiltDist::DirectUrl(DirectUrlBuiltDist {
+                filename: WheelFilename::from_str("anyio-4.3.0-py3-none-any.whl").unwrap(),
+                location: url.to_url(),
+                url,
+            })));
+        assert_eq!(dist.index(), None);
+
+        // The direct URL is within the extra index.
+        let mirror = IndexUrl::from_str("https://mirror.example.com/simple").unwrap();
+        let index_locations = IndexLocations::new(None, vec![mirror.clone()], vec![], false);
+        assert_eq!(dist.index_in(&index_locations), Some(&mirror));
+
+        // The direct URL shares a host, but not a path, with the extra index.
+        let other = IndexUrl::from_str("https://mirror.example.com/sim").unwrap();
+        let index_locations = IndexLocations::new(None, vec![other], vec![], false);
+        assert_eq!(dist.index_in(&index_locations), None);
+
+        // The direct URL is not within any index.
+        assert_eq!(dist.index_in(&IndexLocations::default()), None);
+    }
+
+    #[test]
+    fn verify_hashes() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        fs_err::write(file.path(), "hello world").unwrap();
+        let sha256 = HashDigest::from_str(
+            "sha256:b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+        )
+        .unwrap();
+        let md5 = HashDigest::from_str("md5:5eb63bbbe01eeed093cb22bb8f5acdc3").unwrap();
+
+        // With no hashes, there's nothing to verify.
+        let dist = installed_dist("anyio", "4.3.0");
+        assert!(dist.verify_hashes(file.path()).is_ok());
+
+        let dist = AnnotatedDist {
+            hashes: vec![sha256.clone(), md5],
+            ..installed_dist("anyio", "4.3.0")
+        };
+        assert!(dist.verify_hashes(file.path()).is_ok());
+
+        // The first disagreement is reported.
+        fs_err::write(file.path(), "goodbye world").unwrap();
+        let dist = AnnotatedDist {
+            hashes: vec![sha256],
+            ..installed_dist("anyio", "4.3.0")
+        };
+        let Err(VerifyHashesError::Mismatch(mismatch)) = dist.verify_hashes(file.path()) else {
+            panic!("expected a hash mismatch");
+        };
+        assert_eq!(mismatch.algorithm, HashAlgorithm::Sha256);
+        assert_eq!(
+            mismatch.expected,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+        assert_ne!(mismatch.actual, mismatch.expected);
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use petgraph::Direction;
+use serde_json::{json, Value};
+
+use distribution_types::{DistributionMetadata, Name, ResolvedDist, VersionOrUrlRef};
+use pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::resolution::graph::ResolutionGraphNode;
+use crate::resolution::AnnotatedDist;
+use crate::ResolutionGraph;
+
+/// The version of the SPDX specification that is emitted.
+const SPEC_VERSION: &str = "SPDX-2.3";
+
+/// The SPDX value used when a field is unknown.
+const NOASSERTION: &str = "NOASSERTION";
+
+impl ResolutionGraph {
+    /// Export the resolution as an [SPDX](https://spdx.dev/) SBOM.
+    ///
+    /// Each distinct package (regardless of extras) is emitted as a package, along with its
+    /// download location and checksums. The dependencies between packages are emitted as
+    /// `DEPENDS_ON` relationships.
+    pub fn to_spdx(&self) -> Value {
+        let mut packages = BTreeMap::new();
+        let mut relationships = BTreeSet::new();
+
+        for index in self.petgraph.node_indices() {
+            let ResolutionGraphNode::Dist(dist) = &self.petgraph[index] else {
+                continue;
+            };
+            let element = spdx_id(dist);
+
+            // Extras share a package with the base package.
+            if dist.is_base() {
+                packages.insert(
+                    element.clone(),
+                    json!({
+                        "SPDXID": element,
+                        "name": dist.name(),
+                        "versionInfo": dist.version.to_string(),
+                        "downloadLocation": download_location(dist),
+                        "filesAnalyzed": false,
+                        "checksums": dist.hashes.iter().map(checksum).collect::<Vec<_>>(),
+                    }),
+                );
+            }
+
+            for dependency in self.petgraph.neighbors_directed(index, Direction::Outgoing) {
+                let ResolutionGraphNode::Dist(dependency) = &self.petgraph[dependency] else {
+                    continue;
+                };
+                let dependency = spdx_id(dependency);
+                if dependency != element {
+                    relationships.insert((element.clone(), dependency));
+                }
+            }
+        }
+
+        let created = jiff::Timestamp::now();
+        json!({
+            "spdxVersion": SPEC_VERSION,
+            "dataLicense": "CC0-1.0",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": "uv-resolution",
+            "documentNamespace": format!(
+                "https://spdx.org/spdxdocs/uv-resolution-{}",
+                created.as_nanosecond()
+            ),
+            "creationInfo": {
+                "created": created.strftime("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                "creators": [format!("Tool: uv-{}", uv_version::version())],
+            },
+            "packages": packages.into_values().collect::<Vec<_>>(),
+            "relationships": relationships
+                .into_iter()
+                .map(|(element, related)| json!({
+                    "spdxElementId": element,
+                    "relationshipType": "DEPENDS_ON",
+                    "relatedSpdxElement": related,
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Return the SPDX identifier for a distribution, e.g., `SPDXRef-Package-anyio-4.3.0`.
+///
+/// SPDX identifiers may only contain letters, numbers, `.`, and `-`.
+fn spdx_id(dist: &AnnotatedDist) -> String {
+    format!("SPDXRef-Package-{}-{}", dist.name(), dist.version)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Return the location from which a distribution can be downloaded, if known.
+fn download_location(dist: &AnnotatedDist) -> String {
+    let ResolvedDist::Installable(dist) = &dist.dist else {
+        return NOASSERTION.to_string();
+    };
+    if let Some(file) = dist.file() {
+        return file
+            .url
+            .to_url()
+            .map_or_else(|_| NOASSERTION.to_string(), |url| url.to_string());
+    }
+    match dist.version_or_url() {
+        VersionOrUrlRef::Url(url) => url.to_string(),
+        VersionOrUrlRef::Version(_) => NOASSERTION.to_string(),
+    }
+}
+
+/// Return the SPDX representation of a [`HashDigest`].
+fn checksum(hash: &HashDigest) -> Value {
+    let algorithm = match hash.algorithm() {
+        HashAlgorithm::Md5 => "MD5",
+        HashAlgorithm::Sha256 => "SHA256",
+        HashAlgorithm::Sha384 => "SHA384",
+        HashAlgorithm::Sha512 => "SHA512",
+    };
+    json!({ "algorithm": algorithm, "checksumValue": hash.digest })
+}
@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use distribution_types::{InstalledDist, InstalledRegistryDist, Name, ResolvedDist};
+use pep440_rs::Version;
+use pep508_rs::{MarkerEnvironment, MarkerEnvironmentBuilder, MarkerTree};
+use uv_configuration::ConfigSettings;
+use uv_distribution::Metadata;
+use uv_normalize::{ExtraName, PackageName};
+
+use crate::resolution::{AnnotatedDist, PipReport, ResolutionGraphNode};
+use crate::ResolutionGraph;
+
+/// Create an [`AnnotatedDist`] for an installed distribution, optionally for one of its extras.
+pub(crate) fn annotated_dist(dist: InstalledDist, extra: Option<&str>) -> AnnotatedDist {
+    let name = dist.name().clone();
+    let version = dist.version().clone();
+    AnnotatedDist {
+        dist: ResolvedDist::Installed(dist),
+        version: version.clone(),
+        extra: extra.map(|extra| ExtraName::from_str(extra).unwrap()),
+        dev: None,
+        hashes: vec![],
+        metadata: Metadata {
+            name,
+            version,
+            requires_dist: vec![],
+            requires_python: None,
+            provides_extras: vec![],
+            dev_dependencies: BTreeMap::default(),
+        },
+        cached_direct_url: OnceLock::new(),
+    }
+}
+
+/// Create an [`AnnotatedDist`] for `name` and `version`, installed from a registry.
+pub(crate) fn installed_dist(name: &str, version: &str) -> AnnotatedDist {
+    let name = PackageName::from_str(name).unwrap();
+    let version = Version::from_str(version).unwrap();
+    annotated_dist(
+        InstalledDist::Registry(InstalledRegistryDist {
+            path: PathBuf::from(format!("/site-packages/{name}-{version}.dist-info")),
+            name,
+            version,
+        }),
+        None,
+    )
+}
+
+/// Create a [`MarkerEnvironment`] for CPython 3.12 on Linux.
+pub(crate) fn environment() -> MarkerEnvironment {
+    MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+        implementation_name: "cpython",
+        implementation_version: "3.12.0",
+        os_name: "posix",
+        platform_machine: "x86_64",
+        platform_python_implementation: "CPython",
+        platform_release: "",
+        platform_system: "Linux",
+        platform_version: "",
+        python_full_version: "3.12.0",
+        python_version: "3.12",
+        sys_platform: "linux",
+    })
+    .unwrap()
+}
+
+/// Create a [`ResolutionGraph`] in which each of the given distributions is a direct dependency of
+/// the root.
+pub(crate) fn resolution(dists: impl IntoIterator<Item = InstalledDist>) -> ResolutionGraph {
+    let mut resolution = ResolutionGraph::default();
+    let root = resolution.petgraph.add_node(ResolutionGraphNode::Root);
+    for dist in dists {
+        let index = resolution
+            .petgraph
+            .add_node(ResolutionGraphNode::Dist(annotated_dist(dist, None)));
+        resolution.petgraph.add_edge(root, index, MarkerTree::TRUE);
+    }
+    resolution
+}
+
+/// Create a [`PipReport`] for the [`resolution`] of the given distributions, without any cached
+/// wheels, requirements, or config settings.
+pub(crate) fn report(dists: impl IntoIterator<Item = InstalledDist>) -> PipReport {
+    resolution(dists)
+        .to_pip_report(
+            &[],
+            &[],
+            &environment(),
+            &ConfigSettings::default(),
+            |_, _| {},
+        )
+        .unwrap()
+}